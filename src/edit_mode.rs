@@ -0,0 +1,192 @@
+use crossterm::{event::KeyCode, style::Color};
+
+use crate::terminal_buffer::TerminalBuffer;
+
+/// One pluggable pane of the hex view: a way to render a line of bytes and
+/// a way to turn a keystroke aimed at one of its sub-elements (a nibble, a
+/// character, a bit, ...) into a new byte value.
+pub trait EditMode {
+    fn name(&self) -> &'static str;
+
+    /// How many selectable sub-elements make up one byte in this mode
+    /// (e.g. two nibbles, one character, eight bits).
+    fn sub_count(&self) -> usize;
+
+    /// Terminal columns occupied by one byte's cell, used to lay out
+    /// successive bytes and panes.
+    fn element_width(&self) -> usize;
+
+    fn render_byte(
+        &self,
+        buffer: &mut TerminalBuffer,
+        x: usize,
+        y: usize,
+        byte: u8,
+        highlighted_sub: Option<usize>,
+    );
+
+    /// Turns a keystroke into the byte's new value, if this mode handles
+    /// that key at all.
+    fn apply_key(&self, byte: u8, sub: usize, key: KeyCode) -> Option<u8>;
+}
+
+pub struct HexMode;
+
+impl EditMode for HexMode {
+    fn name(&self) -> &'static str {
+        "HEX"
+    }
+
+    fn sub_count(&self) -> usize {
+        2
+    }
+
+    fn element_width(&self) -> usize {
+        3
+    }
+
+    fn render_byte(
+        &self,
+        buffer: &mut TerminalBuffer,
+        x: usize,
+        y: usize,
+        byte: u8,
+        highlighted_sub: Option<usize>,
+    ) {
+        let (mut left_fg, mut left_bg) = (Color::White, Color::Black);
+        let (mut right_fg, mut right_bg) = (Color::White, Color::Black);
+
+        match highlighted_sub {
+            Some(0) => {
+                left_fg = Color::Black;
+                left_bg = Color::White;
+            }
+            Some(_) => {
+                right_fg = Color::Black;
+                right_bg = Color::White;
+            }
+            None => {}
+        }
+
+        buffer.put_cells(
+            x,
+            y,
+            &format!("{value:1X}", value = (byte >> 4) & 0xf),
+            left_fg,
+            left_bg,
+        );
+        buffer.put_cells(
+            x + 1,
+            y,
+            &format!("{value:1X}", value = byte & 0xf),
+            right_fg,
+            right_bg,
+        );
+    }
+
+    fn apply_key(&self, byte: u8, sub: usize, key: KeyCode) -> Option<u8> {
+        let KeyCode::Char(key) = key else {
+            return None;
+        };
+        let value = key.to_digit(16)? as u8;
+
+        Some(if sub == 0 {
+            byte & 0x0F | (value << 4)
+        } else {
+            byte & 0xF0 | (value & 0xF)
+        })
+    }
+}
+
+pub struct AsciiMode;
+
+impl EditMode for AsciiMode {
+    fn name(&self) -> &'static str {
+        "ASCII"
+    }
+
+    fn sub_count(&self) -> usize {
+        1
+    }
+
+    fn element_width(&self) -> usize {
+        1
+    }
+
+    fn render_byte(
+        &self,
+        buffer: &mut TerminalBuffer,
+        x: usize,
+        y: usize,
+        byte: u8,
+        highlighted_sub: Option<usize>,
+    ) {
+        let (fg, bg) = match highlighted_sub {
+            Some(_) => (Color::Black, Color::White),
+            None => (Color::White, Color::Black),
+        };
+
+        let ch = if byte.is_ascii_graphic() { byte as char } else { '.' };
+        buffer.put_cell(x, y, ch, fg, bg);
+    }
+
+    fn apply_key(&self, _byte: u8, _sub: usize, key: KeyCode) -> Option<u8> {
+        let KeyCode::Char(key) = key else {
+            return None;
+        };
+
+        if key.is_ascii() && (key.is_ascii_graphic() || key == ' ') {
+            Some(key as u8)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct BinaryMode;
+
+impl EditMode for BinaryMode {
+    fn name(&self) -> &'static str {
+        "BIN"
+    }
+
+    fn sub_count(&self) -> usize {
+        8
+    }
+
+    fn element_width(&self) -> usize {
+        9
+    }
+
+    fn render_byte(
+        &self,
+        buffer: &mut TerminalBuffer,
+        x: usize,
+        y: usize,
+        byte: u8,
+        highlighted_sub: Option<usize>,
+    ) {
+        for bit in 0..8 {
+            let (fg, bg) = if highlighted_sub == Some(bit) {
+                (Color::Black, Color::White)
+            } else {
+                (Color::White, Color::Black)
+            };
+
+            let ch = if (byte >> (7 - bit)) & 1 == 1 { '1' } else { '0' };
+            buffer.put_cell(x + bit, y, ch, fg, bg);
+        }
+    }
+
+    fn apply_key(&self, byte: u8, sub: usize, key: KeyCode) -> Option<u8> {
+        let KeyCode::Char(key) = key else {
+            return None;
+        };
+        if key != '0' && key != '1' {
+            return None;
+        }
+
+        let mask = 1u8 << (7 - sub);
+        Some(if key == '1' { byte | mask } else { byte & !mask })
+    }
+}
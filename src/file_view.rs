@@ -0,0 +1,492 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+const DEFAULT_CACHE_SIZE: usize = 64 * 1024;
+
+/// Windowed view over a `File` that keeps only a fixed-size cache of bytes
+/// in memory, so opening a multi-gigabyte file doesn't require reading it
+/// whole. Edits are layered on top of the cache in a sparse dirty map so a
+/// modified byte always reads back its new value, even after the cache
+/// window has been refilled over it.
+pub struct CachingFileView {
+    file: File,
+    path: PathBuf,
+    len: u64,
+    cache: Vec<u8>,
+    cache_start: u64,
+    cache_len: usize,
+    cache_size: usize,
+    dirty: HashMap<u64, u8>,
+}
+
+impl CachingFileView {
+    pub fn new(file: File, path: impl Into<PathBuf>) -> io::Result<Self> {
+        Self::with_cache_size(file, path.into(), DEFAULT_CACHE_SIZE)
+    }
+
+    fn with_cache_size(mut file: File, path: PathBuf, cache_size: usize) -> io::Result<Self> {
+        let len = file.metadata()?.len();
+
+        file.seek(SeekFrom::Start(0))?;
+        let mut cache = vec![0u8; cache_size];
+        let cache_len = Self::fill(&mut file, &mut cache)?;
+
+        Ok(Self {
+            file,
+            path,
+            len,
+            cache,
+            cache_start: 0,
+            cache_len,
+            cache_size,
+            dirty: HashMap::new(),
+        })
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn fill(file: &mut File, cache: &mut [u8]) -> io::Result<usize> {
+        let mut total_read = 0;
+        while total_read < cache.len() {
+            match file.read(&mut cache[total_read..])? {
+                0 => break,
+                n => total_read += n,
+            }
+        }
+        Ok(total_read)
+    }
+
+    /// Makes sure `[offset, offset + len)` is covered by the cache,
+    /// re-seeking and refilling around the requested range if it isn't.
+    fn ensure_window(&mut self, offset: u64, len: usize) -> io::Result<()> {
+        let cache_end = self.cache_start + self.cache_len as u64;
+        if offset >= self.cache_start && offset + len as u64 <= cache_end {
+            return Ok(());
+        }
+
+        let half_cache = (self.cache_size / 2) as u64;
+        let new_start = offset.saturating_sub(half_cache);
+
+        self.file.seek(SeekFrom::Start(new_start))?;
+        self.cache_len = Self::fill(&mut self.file, &mut self.cache)?;
+        self.cache_start = new_start;
+
+        Ok(())
+    }
+
+    pub fn get_byte(&mut self, offset: u64) -> Option<u8> {
+        if let Some(byte) = self.dirty.get(&offset) {
+            return Some(*byte);
+        }
+
+        if offset >= self.len || self.ensure_window(offset, 1).is_err() {
+            return None;
+        }
+
+        let index = (offset - self.cache_start) as usize;
+        self.cache.get(index).copied()
+    }
+
+    pub fn get_bytes(&mut self, offset: u64, len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len);
+        for i in 0..len as u64 {
+            match self.get_byte(offset + i) {
+                Some(byte) => bytes.push(byte),
+                None => break,
+            }
+        }
+        bytes
+    }
+
+    pub fn set_byte(&mut self, offset: u64, value: u8) {
+        self.dirty.insert(offset, value);
+    }
+
+    /// Writes every dirty byte back to the underlying file.
+    pub fn save(&mut self) -> io::Result<()> {
+        for (offset, value) in self.dirty.iter() {
+            self.file.seek(SeekFrom::Start(*offset))?;
+            self.file.write_all(&[*value])?;
+        }
+        Ok(())
+    }
+
+    /// A sibling path (`<name>.tmp`) used to stage a full rewrite of the
+    /// file before it's swapped into place.
+    fn tmp_path(&self) -> PathBuf {
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".tmp");
+        self.path.with_file_name(file_name)
+    }
+
+    /// Opens a fresh temporary file, created alongside the original, for a
+    /// caller to stream a full rewrite into before calling
+    /// [`Self::finish_replace`]. Reads against `self` stay valid for as
+    /// long as the caller likes, since this never touches `self.file`.
+    fn begin_replace(&self) -> io::Result<File> {
+        File::options()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.tmp_path())
+    }
+
+    /// Finishes a rewrite started with [`Self::begin_replace`]: flushes
+    /// `tmp_file`, atomically renames it over the original, and resets the
+    /// cache/dirty state around the new, `total_len`-byte content.
+    fn finish_replace(&mut self, mut tmp_file: File, total_len: u64) -> io::Result<()> {
+        tmp_file.flush()?;
+        drop(tmp_file);
+
+        std::fs::rename(self.tmp_path(), &self.path)?;
+        self.file = File::options().read(true).write(true).open(&self.path)?;
+
+        self.len = total_len;
+        self.dirty.clear();
+
+        self.file.seek(SeekFrom::Start(0))?;
+        self.cache_len = Self::fill(&mut self.file, &mut self.cache)?;
+        self.cache_start = 0;
+
+        Ok(())
+    }
+}
+
+/// A contiguous run of logical bytes: either untouched bytes still backed by
+/// the original file, or bytes inserted by an edit.
+#[derive(Clone)]
+enum Piece {
+    Base { offset: u64, len: u64 },
+    Inserted(Vec<u8>),
+}
+
+impl Piece {
+    fn len(&self) -> u64 {
+        match self {
+            Piece::Base { len, .. } => *len,
+            Piece::Inserted(bytes) => bytes.len() as u64,
+        }
+    }
+}
+
+/// A single undoable edit, recorded with enough information to invert it.
+pub enum Edit {
+    Insert { offset: u64, value: u8 },
+    Delete { offset: u64, old: u8 },
+    Update { offset: u64, old: u8, new: u8 },
+}
+
+/// Byte-level insert/delete/update on top of a `CachingFileView`, tracked as
+/// a piece table so every mutation only touches the pieces it shifts rather
+/// than the whole file. Every mutating call records the edit on an undo
+/// stack and clears the redo stack; `undo` applies the edit's inverse,
+/// matching the usual editor undo model.
+pub struct EditableView {
+    file_view: CachingFileView,
+    pieces: Vec<Piece>,
+    undo_stack: Vec<Edit>,
+    redo_stack: Vec<Edit>,
+}
+
+impl EditableView {
+    pub fn new(file_view: CachingFileView) -> Self {
+        let len = file_view.len();
+        let pieces = if len > 0 {
+            vec![Piece::Base { offset: 0, len }]
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            file_view,
+            pieces,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.pieces.iter().map(Piece::len).sum()
+    }
+
+    fn locate(&self, offset: u64) -> Option<(usize, u64)> {
+        let mut start = 0u64;
+        for (index, piece) in self.pieces.iter().enumerate() {
+            let len = piece.len();
+            if offset < start + len {
+                return Some((index, offset - start));
+            }
+            start += len;
+        }
+        None
+    }
+
+    pub fn get_byte(&mut self, offset: u64) -> Option<u8> {
+        let (index, local) = self.locate(offset)?;
+        match self.pieces[index].clone() {
+            Piece::Base { offset: base_offset, .. } => self.file_view.get_byte(base_offset + local),
+            Piece::Inserted(bytes) => bytes.get(local as usize).copied(),
+        }
+    }
+
+    /// Reads `len` bytes starting at `offset`, batching each contiguous run
+    /// within a single piece into one `CachingFileView::get_bytes` call
+    /// (for `Base` pieces) or one slice copy (for `Inserted` pieces)
+    /// instead of going through `get_byte` one offset at a time.
+    pub fn get_bytes(&mut self, offset: u64, len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len);
+        let mut remaining = len as u64;
+        let mut pos = offset;
+
+        while remaining > 0 {
+            let Some((index, local)) = self.locate(pos) else {
+                break;
+            };
+
+            let take = std::cmp::min(remaining, self.pieces[index].len() - local);
+            match self.pieces[index].clone() {
+                Piece::Base { offset: base_offset, .. } => {
+                    let chunk = self.file_view.get_bytes(base_offset + local, take as usize);
+                    let got = chunk.len() as u64;
+                    bytes.extend(chunk);
+                    if got < take {
+                        break;
+                    }
+                    remaining -= got;
+                    pos += got;
+                }
+                Piece::Inserted(inserted) => {
+                    let start = local as usize;
+                    let end = start + take as usize;
+                    bytes.extend_from_slice(&inserted[start..end]);
+                    remaining -= take;
+                    pos += take;
+                }
+            }
+        }
+
+        bytes
+    }
+
+    fn insert_byte_raw(&mut self, offset: u64, value: u8) {
+        match self.locate(offset) {
+            Some((index, local)) => match self.pieces[index].clone() {
+                Piece::Base { offset: base_offset, len } => {
+                    let mut split = Vec::with_capacity(3);
+                    if local > 0 {
+                        split.push(Piece::Base { offset: base_offset, len: local });
+                    }
+                    split.push(Piece::Inserted(vec![value]));
+                    if local < len {
+                        split.push(Piece::Base {
+                            offset: base_offset + local,
+                            len: len - local,
+                        });
+                    }
+                    self.pieces.splice(index..=index, split);
+                    self.coalesce_pieces();
+                }
+                Piece::Inserted(mut bytes) => {
+                    bytes.insert(local as usize, value);
+                    self.pieces[index] = Piece::Inserted(bytes);
+                }
+            },
+            None => self.pieces.push(Piece::Inserted(vec![value])),
+        }
+    }
+
+    fn delete_byte_raw(&mut self, offset: u64) -> Option<u8> {
+        let (index, local) = self.locate(offset)?;
+        match self.pieces[index].clone() {
+            Piece::Base { offset: base_offset, len } => {
+                let old = self.file_view.get_byte(base_offset + local)?;
+
+                let mut split = Vec::with_capacity(2);
+                if local > 0 {
+                    split.push(Piece::Base { offset: base_offset, len: local });
+                }
+                if local + 1 < len {
+                    split.push(Piece::Base {
+                        offset: base_offset + local + 1,
+                        len: len - local - 1,
+                    });
+                }
+                self.pieces.splice(index..=index, split);
+                self.coalesce_pieces();
+
+                Some(old)
+            }
+            Piece::Inserted(mut bytes) => {
+                let old = bytes.remove(local as usize);
+                if bytes.is_empty() {
+                    self.pieces.remove(index);
+                    self.coalesce_pieces();
+                } else {
+                    self.pieces[index] = Piece::Inserted(bytes);
+                }
+                Some(old)
+            }
+        }
+    }
+
+    /// Merges adjacent `Base` pieces that are contiguous in the underlying
+    /// file. Deleting a byte (or undoing an insert) can leave two `Base`
+    /// pieces that used to be one split across an index boundary; without
+    /// this, repeated insert/delete cycles at the same offset would
+    /// fragment `self.pieces` without bound.
+    fn coalesce_pieces(&mut self) {
+        let mut i = 0;
+        while i + 1 < self.pieces.len() {
+            let (Piece::Base { offset: a_offset, len: a_len }, Piece::Base { offset: b_offset, len: b_len }) =
+                (&self.pieces[i], &self.pieces[i + 1])
+            else {
+                i += 1;
+                continue;
+            };
+
+            if a_offset + a_len != *b_offset {
+                i += 1;
+                continue;
+            }
+
+            let merged = Piece::Base {
+                offset: *a_offset,
+                len: a_len + b_len,
+            };
+            self.pieces.splice(i..=i + 1, [merged]);
+        }
+    }
+
+    fn update_byte_raw(&mut self, offset: u64, value: u8) -> Option<u8> {
+        let (index, local) = self.locate(offset)?;
+        let old = self.get_byte(offset)?;
+
+        match &mut self.pieces[index] {
+            Piece::Base { offset: base_offset, .. } => {
+                self.file_view.set_byte(*base_offset + local, value);
+            }
+            Piece::Inserted(bytes) => bytes[local as usize] = value,
+        }
+
+        Some(old)
+    }
+
+    pub fn insert_byte(&mut self, offset: u64, value: u8) {
+        self.insert_byte_raw(offset, value);
+        self.undo_stack.push(Edit::Insert { offset, value });
+        self.redo_stack.clear();
+    }
+
+    pub fn delete_byte(&mut self, offset: u64) -> Option<u8> {
+        let old = self.delete_byte_raw(offset)?;
+        self.undo_stack.push(Edit::Delete { offset, old });
+        self.redo_stack.clear();
+        Some(old)
+    }
+
+    pub fn update_byte(&mut self, offset: u64, value: u8) {
+        if let Some(old) = self.update_byte_raw(offset, value) {
+            self.undo_stack.push(Edit::Update {
+                offset,
+                old,
+                new: value,
+            });
+            self.redo_stack.clear();
+        }
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo_stack.pop() else {
+            return false;
+        };
+
+        match edit {
+            Edit::Insert { offset, .. } => {
+                self.delete_byte_raw(offset);
+            }
+            Edit::Delete { offset, old } => self.insert_byte_raw(offset, old),
+            Edit::Update { offset, old, .. } => {
+                self.update_byte_raw(offset, old);
+            }
+        }
+
+        self.redo_stack.push(edit);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo_stack.pop() else {
+            return false;
+        };
+
+        match edit {
+            Edit::Insert { offset, value } => self.insert_byte_raw(offset, value),
+            Edit::Delete { offset, .. } => {
+                self.delete_byte_raw(offset);
+            }
+            Edit::Update { offset, new, .. } => {
+                self.update_byte_raw(offset, new);
+            }
+        }
+
+        self.undo_stack.push(edit);
+        true
+    }
+
+    pub fn pending_edits(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// True when no insert/delete has shifted the logical byte stream, i.e.
+    /// the pieces are a single `Base` run covering the whole original file,
+    /// so the stream can differ from the file only by overwritten bytes.
+    fn is_unshifted(&self) -> bool {
+        matches!(self.pieces.as_slice(), [Piece::Base { offset: 0, len }] if *len == self.file_view.len())
+    }
+
+    /// Applies the journal to the file. Plain byte overwrites are patched in
+    /// place; once an insert or delete has shifted anything, the whole
+    /// logical byte stream is streamed out in bounded chunks to a temporary
+    /// file that's then swapped into place, rather than buffering the
+    /// entire (potentially multi-gigabyte) file in memory at once.
+    pub fn save(&mut self) -> io::Result<()> {
+        if self.is_unshifted() {
+            self.file_view.save()?;
+            self.undo_stack.clear();
+            self.redo_stack.clear();
+            return Ok(());
+        }
+
+        const SAVE_CHUNK_SIZE: usize = 64 * 1024;
+
+        let total_len = self.len();
+        let mut tmp_file = self.file_view.begin_replace()?;
+
+        let mut offset = 0u64;
+        while offset < total_len {
+            let chunk_len = std::cmp::min(SAVE_CHUNK_SIZE as u64, total_len - offset) as usize;
+            let chunk = self.get_bytes(offset, chunk_len);
+            if chunk.is_empty() {
+                break;
+            }
+            tmp_file.write_all(&chunk)?;
+            offset += chunk.len() as u64;
+        }
+
+        self.file_view.finish_replace(tmp_file, total_len)?;
+        self.pieces = vec![Piece::Base { offset: 0, len: total_len }];
+
+        // The file on disk now matches the logical stream exactly, so the
+        // recorded edits can no longer be undone against it.
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+
+        Ok(())
+    }
+}
@@ -1,15 +1,14 @@
+mod edit_mode;
+mod file_view;
 mod screen_state;
 mod terminal_buffer;
 
+use edit_mode::{AsciiMode, BinaryMode, EditMode, HexMode};
+use file_view::{CachingFileView, EditableView};
 use screen_state::ScreenState;
 use terminal_buffer::{apply_patches, TerminalBuffer};
 
-use std::{
-    fs::File,
-    io::{self, Read, Seek, Write},
-    result,
-    time::Duration,
-};
+use std::{fs::File, io, io::Write, result, time::Duration};
 
 use crossterm::{
     event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers},
@@ -21,6 +20,13 @@ type Result<T> = result::Result<T, ()>;
 
 const BYTES_PER_LINE: usize = 16;
 
+/// Normal-mode key bindings (navigation, save, insert/delete, undo, the
+/// goto prompt) that always win over the focused mode's `apply_key`, even
+/// in a mode like `AsciiMode` that would otherwise treat them as printable
+/// characters to write. Without this, there would be no way to navigate,
+/// save, or undo while the ASCII or Binary pane is focused.
+const RESERVED_NORMAL_KEYS: &[char] = &['h', 'j', 'k', 'l', 's', 'i', 'x', 'u', ':', 'g'];
+
 fn print_usage() {
     println!("Usage: hex_editor <file path>");
 }
@@ -47,63 +53,173 @@ impl HexViewLine {
     }
 }
 
+#[derive(Default)]
 struct Cursor {
     x: usize,
     y: usize,
     is_visible: bool,
-    is_left_nibble: bool,
-}
-
-impl Default for Cursor {
-    fn default() -> Self {
-        Self {
-            x: 0,
-            y: 0,
-            is_visible: false,
-            is_left_nibble: true,
-        }
-    }
+    /// Which sub-element of the byte at (x, y) is selected — a nibble, a
+    /// whole character, or a bit, depending on the focused `EditMode`.
+    sub: usize,
 }
 
 struct HexView {
-    lines: Vec<HexViewLine>,
+    file_view: EditableView,
+    total_lines: usize,
     cursor: Cursor,
+    scroll_top: usize,
+    modes: Vec<Box<dyn EditMode>>,
+    focused_mode: usize,
 }
 
 impl HexView {
-    fn new(data: &[u8]) -> Self {
-        let mut hex_editor_lines = Vec::new();
-
-        let mut offset = 0;
-        while offset < data.len() {
-            let line_bytes;
-            if offset + BYTES_PER_LINE > data.len() {
-                line_bytes = &data[offset..];
-            } else {
-                line_bytes = &data[offset..(offset + BYTES_PER_LINE)];
-            }
+    fn new(file_view: EditableView) -> Self {
+        let total_lines = (file_view.len() as usize).div_ceil(BYTES_PER_LINE);
 
-            hex_editor_lines.push(HexViewLine::new(format!("{offset:08X}"), &line_bytes));
+        Self {
+            file_view,
+            total_lines,
+            cursor: Cursor::default(),
+            scroll_top: 0,
+            modes: vec![Box::new(HexMode), Box::new(AsciiMode), Box::new(BinaryMode)],
+            focused_mode: 0,
+        }
+    }
+
+    fn cycle_mode(&mut self) {
+        self.focused_mode = (self.focused_mode + 1) % self.modes.len();
+        self.cursor.sub = 0;
+    }
+
+    fn focused_mode_name(&self) -> &'static str {
+        self.modes[self.focused_mode].name()
+    }
 
-            offset += BYTES_PER_LINE;
+    fn last_line_index(&self) -> usize {
+        self.total_lines.saturating_sub(1)
+    }
+
+    fn max_scroll_top(&self, visible_rows: usize) -> usize {
+        self.total_lines.saturating_sub(visible_rows)
+    }
+
+    fn scroll_to_cursor(&mut self, visible_rows: usize) {
+        if self.cursor.y < self.scroll_top {
+            self.scroll_top = self.cursor.y;
+        } else if self.cursor.y >= self.scroll_top + visible_rows {
+            self.scroll_top = self.cursor.y + 1 - visible_rows;
         }
+    }
 
-        Self {
-            lines: hex_editor_lines,
-            cursor: Cursor::default(),
+    fn page_down(&mut self, visible_rows: usize) {
+        if !self.cursor.is_visible {
+            self.cursor.is_visible = true;
+            return;
         }
+
+        self.scroll_top = std::cmp::min(
+            self.scroll_top + visible_rows,
+            self.max_scroll_top(visible_rows),
+        );
+        self.cursor.y = std::cmp::min(self.cursor.y + visible_rows, self.last_line_index());
     }
 
-    fn get_selected_byte(&mut self) -> Option<&mut u8> {
-        let x = self.cursor.x;
-        let y = self.cursor.y;
+    fn page_up(&mut self, visible_rows: usize) {
+        if !self.cursor.is_visible {
+            self.cursor.is_visible = true;
+            return;
+        }
 
-        if let Some(line) = self.lines.get_mut(y) {
-            if let Some(data_byte) = line.bytes.get_mut(x) {
-                return Some(data_byte);
+        self.scroll_top = self.scroll_top.saturating_sub(visible_rows);
+        self.cursor.y = self.cursor.y.saturating_sub(visible_rows);
+    }
+
+    fn half_page_down(&mut self, visible_rows: usize) {
+        self.page_down(std::cmp::max(visible_rows / 2, 1));
+    }
+
+    fn half_page_up(&mut self, visible_rows: usize) {
+        self.page_up(std::cmp::max(visible_rows / 2, 1));
+    }
+
+    fn selected_offset(&self) -> u64 {
+        (self.cursor.y * BYTES_PER_LINE + self.cursor.x) as u64
+    }
+
+    fn get_selected_byte(&mut self) -> Option<u8> {
+        self.file_view.get_byte(self.selected_offset())
+    }
+
+    /// Tries to let the focused mode turn `key` into a new byte value at the
+    /// cursor. Returns whether it did, so callers can fall back to treating
+    /// the key as a Normal-mode binding when the mode didn't want it.
+    fn handle_edit_key(&mut self, key: KeyCode) -> bool {
+        if let KeyCode::Char(c) = key {
+            if RESERVED_NORMAL_KEYS.contains(&c) {
+                return false;
             }
         }
-        None
+
+        let offset = self.selected_offset();
+        let sub = self.cursor.sub;
+
+        let Some(byte) = self.get_selected_byte() else {
+            return false;
+        };
+        let Some(new_byte) = self.modes[self.focused_mode].apply_key(byte, sub, key) else {
+            return false;
+        };
+
+        self.file_view.update_byte(offset, new_byte);
+        true
+    }
+
+    fn after_structural_edit(&mut self, visible_rows: usize) {
+        self.total_lines = (self.file_view.len() as usize).div_ceil(BYTES_PER_LINE);
+        self.cursor.y = std::cmp::min(self.cursor.y, self.last_line_index());
+        self.scroll_to_cursor(visible_rows);
+    }
+
+    fn insert_byte_at_cursor(&mut self, visible_rows: usize) {
+        let offset = self.selected_offset();
+        self.file_view.insert_byte(offset, 0);
+        self.after_structural_edit(visible_rows);
+    }
+
+    fn delete_byte_at_cursor(&mut self, visible_rows: usize) {
+        let offset = self.selected_offset();
+        self.file_view.delete_byte(offset);
+        self.after_structural_edit(visible_rows);
+    }
+
+    fn undo(&mut self, visible_rows: usize) {
+        if self.file_view.undo() {
+            self.after_structural_edit(visible_rows);
+        }
+    }
+
+    fn redo(&mut self, visible_rows: usize) {
+        if self.file_view.redo() {
+            self.after_structural_edit(visible_rows);
+        }
+    }
+
+    fn pending_edits(&self) -> usize {
+        self.file_view.pending_edits()
+    }
+
+    /// Moves the cursor (and scrolls the viewport if needed) to an absolute
+    /// byte offset, clamped to the file's length.
+    fn set_cursor_to_offset(&mut self, offset: u64, visible_rows: usize) {
+        let max_offset = self.file_view.len().saturating_sub(1);
+        let offset = std::cmp::min(offset, max_offset) as usize;
+
+        self.cursor.is_visible = true;
+        self.cursor.y = offset / BYTES_PER_LINE;
+        self.cursor.x = offset % BYTES_PER_LINE;
+        self.cursor.sub = 0;
+
+        self.scroll_to_cursor(visible_rows);
     }
 
     fn move_cursor_left(&mut self) {
@@ -112,17 +228,13 @@ impl HexView {
             return;
         }
 
-        if !self.cursor.is_left_nibble {
-            self.cursor.is_left_nibble = true;
-        } else {
-            if self.cursor.x == 0 && self.cursor.is_left_nibble {
-                return;
-            }
+        let sub_count = self.modes[self.focused_mode].sub_count();
 
-            self.cursor.is_left_nibble = false;
-            if let Some(_) = self.cursor.x.checked_sub(1) {
-                self.cursor.x -= 1;
-            }
+        if self.cursor.sub > 0 {
+            self.cursor.sub -= 1;
+        } else if self.cursor.x > 0 {
+            self.cursor.x -= 1;
+            self.cursor.sub = sub_count - 1;
         }
     }
 
@@ -132,19 +244,17 @@ impl HexView {
             return;
         }
 
-        if self.cursor.is_left_nibble {
-            self.cursor.is_left_nibble = false;
-        } else {
-            if self.cursor.x == (BYTES_PER_LINE - 1) && !self.cursor.is_left_nibble {
-                return;
-            }
+        let sub_count = self.modes[self.focused_mode].sub_count();
 
-            self.cursor.is_left_nibble = true;
-            self.cursor.x = std::cmp::min(self.cursor.x + 1, BYTES_PER_LINE - 1);
+        if self.cursor.sub + 1 < sub_count {
+            self.cursor.sub += 1;
+        } else if self.cursor.x + 1 < BYTES_PER_LINE {
+            self.cursor.x += 1;
+            self.cursor.sub = 0;
         }
     }
 
-    fn move_cursor_up(&mut self) {
+    fn move_cursor_up(&mut self, visible_rows: usize) {
         if !self.cursor.is_visible {
             self.cursor.is_visible = true;
             return;
@@ -153,105 +263,130 @@ impl HexView {
         if let Some(_) = self.cursor.y.checked_sub(1) {
             self.cursor.y -= 1;
         }
+
+        self.scroll_to_cursor(visible_rows);
     }
 
-    fn move_cursor_down(&mut self) {
+    fn move_cursor_down(&mut self, visible_rows: usize) {
         if !self.cursor.is_visible {
             self.cursor.is_visible = true;
             return;
         }
 
-        self.cursor.y = std::cmp::min(self.cursor.y + 1, self.lines.len() - 1);
+        self.cursor.y = std::cmp::min(self.cursor.y + 1, self.last_line_index());
+
+        self.scroll_to_cursor(visible_rows);
     }
 
-    fn get_lines(&self) -> &Vec<HexViewLine> {
-        &self.lines
+    fn materialize_line(&mut self, line_index: usize) -> HexViewLine {
+        let offset = (line_index * BYTES_PER_LINE) as u64;
+        let bytes = self.file_view.get_bytes(offset, BYTES_PER_LINE);
+        HexViewLine::new(format!("{offset:08X}"), &bytes)
     }
 
-    fn get_data_as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-        for line in self.lines.iter() {
-            for byte_data in line.bytes.iter() {
-                bytes.push(*byte_data);
-            }
-        }
-        bytes
+    fn materialize_visible_lines(&mut self, visible_rows: usize) -> Vec<HexViewLine> {
+        let end = std::cmp::min(self.scroll_top + visible_rows, self.total_lines);
+        (self.scroll_top..end)
+            .map(|line_index| self.materialize_line(line_index))
+            .collect()
+    }
+
+    fn save(&mut self) -> io::Result<()> {
+        self.file_view.save()
     }
 }
 
+const PANE_GAP: usize = 2;
+const OFFSET_LABEL_WIDTH: usize = 11;
+
 fn render_hex_editor(
     buffer: &mut TerminalBuffer,
-    hex_editor: &HexView,
+    hex_editor: &mut HexView,
     x_start: usize,
     y_start: usize,
+    visible_rows: usize,
 ) {
-    for (y, hex_editor_line) in hex_editor.get_lines().iter().enumerate() {
+    let scroll_top = hex_editor.scroll_top;
+    let focused_mode = hex_editor.focused_mode;
+    let cursor_cell = {
+        let cursor = &hex_editor.cursor;
+        cursor.is_visible.then_some((cursor.y, cursor.x, cursor.sub))
+    };
+
+    let mut pane_x = Vec::with_capacity(hex_editor.modes.len());
+    let mut x = x_start + OFFSET_LABEL_WIDTH;
+    for mode in hex_editor.modes.iter() {
+        pane_x.push(x);
+        x += mode.element_width() * BYTES_PER_LINE + PANE_GAP;
+    }
+
+    let lines = hex_editor.materialize_visible_lines(visible_rows);
+
+    for (rel_y, hex_editor_line) in lines.iter().enumerate() {
+        let y = scroll_top + rel_y;
+
         buffer.put_cells(
             x_start,
-            y + y_start,
+            rel_y + y_start,
             &format!("{offset}:", offset = hex_editor_line.offset),
             Color::White,
             Color::Black,
         );
 
-        let start_hex = 11;
-        for (x, byte_data) in hex_editor_line.bytes.iter().enumerate() {
-            let mut left_nibble_fg = Color::White;
-            let mut left_nibble_bg = Color::Black;
-
-            let mut right_nibble_fg = Color::White;
-            let mut right_nibble_bg = Color::Black;
-
-            if hex_editor.cursor.is_visible {
-                if hex_editor.cursor.y == y && hex_editor.cursor.x == x {
-                    if hex_editor.cursor.is_left_nibble {
-                        left_nibble_fg = Color::Black;
-                        left_nibble_bg = Color::White;
-                    } else {
-                        right_nibble_fg = Color::Black;
-                        right_nibble_bg = Color::White;
+        for (mode_index, mode) in hex_editor.modes.iter().enumerate() {
+            for (x, byte) in hex_editor_line.bytes.iter().enumerate() {
+                let highlighted_sub = match cursor_cell {
+                    Some((cy, cx, sub)) if mode_index == focused_mode && cy == y && cx == x => {
+                        Some(sub)
                     }
-                }
-            }
-            buffer.put_cells(
-                x_start + start_hex + x * 3,
-                y + y_start,
-                &format!("{value:1X}", value = (byte_data >> 4) & 0xf),
-                left_nibble_fg,
-                left_nibble_bg,
-            );
-            buffer.put_cells(
-                x_start + start_hex + 1 + x * 3,
-                y + y_start,
-                &format!("{value:1X}", value = byte_data & 0xf),
-                right_nibble_fg,
-                right_nibble_bg,
-            );
-        }
-
-        let start_asci = 11 + 3 * BYTES_PER_LINE - 1 + 2;
-        for (x, byte_data) in hex_editor_line.bytes.iter().enumerate() {
-            if byte_data.is_ascii_graphic() {
-                buffer.put_cell(
-                    x_start + start_asci + x,
-                    y + y_start,
-                    *byte_data as char,
-                    Color::White,
-                    Color::Black,
-                );
-            } else {
-                buffer.put_cell(
-                    x_start + start_asci + x,
-                    y + y_start,
-                    '.',
-                    Color::White,
-                    Color::Black,
+                    _ => None,
+                };
+
+                mode.render_byte(
+                    buffer,
+                    pane_x[mode_index] + x * mode.element_width(),
+                    rel_y + y_start,
+                    *byte,
+                    highlighted_sub,
                 );
             }
         }
     }
 }
 
+/// Whether keystrokes are routed to the hex view's navigation/edit bindings
+/// or captured into the command line at the bottom of the screen.
+enum InputMode {
+    Normal,
+    Command,
+}
+
+/// Parses a `:`-command-line offset expression: `0x`-prefixed hex, plain
+/// decimal, or `+`/`-` prefixed decimal/hex relative to `current_offset`.
+fn parse_offset(input: &str, current_offset: u64) -> Option<u64> {
+    let input = input.trim();
+
+    if let Some(delta) = input.strip_prefix('+') {
+        let delta = parse_u64(delta)?;
+        return Some(current_offset.saturating_add(delta));
+    }
+
+    if let Some(delta) = input.strip_prefix('-') {
+        let delta = parse_u64(delta)?;
+        return Some(current_offset.saturating_sub(delta));
+    }
+
+    parse_u64(input)
+}
+
+fn parse_u64(input: &str) -> Option<u64> {
+    if let Some(hex) = input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok()
+    } else {
+        input.parse().ok()
+    }
+}
+
 fn status_bar(
     buffer: &mut TerminalBuffer,
     label: &str,
@@ -280,7 +415,7 @@ fn main() -> Result<()> {
         }
     };
 
-    let mut file = File::options()
+    let file = File::options()
         .write(true)
         .read(true)
         .open(file_path.clone())
@@ -288,10 +423,8 @@ fn main() -> Result<()> {
             eprintln!("Could not open file: {file_path}: {err}");
         })?;
 
-    let mut data = Vec::new();
-
-    file.read_to_end(&mut data).map_err(|err| {
-        eprintln!("Could not read file into buffer: {err}");
+    let file_view = CachingFileView::new(file, file_path).map_err(|err| {
+        eprintln!("Could not read file: {err}");
     })?;
 
     let _screen_state = ScreenState::enable().map_err(|err| {
@@ -305,12 +438,24 @@ fn main() -> Result<()> {
     let mut buffer = TerminalBuffer::new(width.into(), height.into());
     let mut prev_buffer = TerminalBuffer::new(width.into(), height.into());
 
-    let mut hex_view = HexView::new(&data);
+    let mut hex_view = HexView::new(EditableView::new(file_view));
+
+    // Row 0 is the title bar and the last row is the status bar, so the
+    // remaining rows are what's actually available to the hex view.
+    let visible_rows = (height as usize).saturating_sub(2);
 
     let mut status_label = String::default();
 
-    status_bar(&mut prev_buffer, "HexEditor", 0, 0, width.into(), Color::Black, Color::White);
-    render_hex_editor(&mut prev_buffer, &hex_view, 0, 1);
+    status_bar(
+        &mut prev_buffer,
+        &format!("HexEditor [{}]", hex_view.focused_mode_name()),
+        0,
+        0,
+        width.into(),
+        Color::Black,
+        Color::White,
+    );
+    render_hex_editor(&mut prev_buffer, &mut hex_view, 0, 1, visible_rows);
     status_bar(&mut prev_buffer, &status_label, 0, height as usize - 1, width.into(), Color::Black, Color::White);
 
     prev_buffer.flush(&mut stdout).map_err(|err| {
@@ -318,65 +463,124 @@ fn main() -> Result<()> {
     })?;
 
     let mut quit = false;
+    let mut input_mode = InputMode::Normal;
+    let mut command_buffer = String::new();
 
     while !quit {
         if poll(Duration::ZERO).unwrap() {
             match read().unwrap() {
                 Event::Key(key_event) => {
                     if key_event.kind == KeyEventKind::Press {
-                        match key_event.code {
-                            KeyCode::Char(key) if key_event.modifiers == KeyModifiers::CONTROL => {
-                                match key {
-                                    'c' => quit = true,
-                                    _ => {}
-                                }
-                            }
-                            KeyCode::Char(key) if key.is_digit(16) => {
-                                if hex_view.cursor.is_visible {
-                                    let left_nibble = hex_view.cursor.is_left_nibble;
-                                    if let Some(byte_under_cursor) = hex_view.get_selected_byte() {
-                                        if left_nibble {
-                                            *byte_under_cursor = *byte_under_cursor & 0xF
-                                                | (key.to_digit(16).unwrap() as u8) << 4;
-                                        } else {
-                                            *byte_under_cursor = *byte_under_cursor & 0xF0
-                                                | key.to_digit(16).unwrap() as u8 & 0xF
+                        match input_mode {
+                            InputMode::Normal => match key_event.code {
+                                KeyCode::Char(key) if key_event.modifiers == KeyModifiers::CONTROL => {
+                                    match key {
+                                        'c' => quit = true,
+                                        'd' => {
+                                            hex_view.half_page_down(visible_rows);
+                                            status_label.clear();
                                         }
+                                        'u' => {
+                                            hex_view.half_page_up(visible_rows);
+                                            status_label.clear();
+                                        }
+                                        'r' => {
+                                            hex_view.redo(visible_rows);
+                                            status_label.clear();
+                                        }
+                                        _ => {}
                                     }
                                 }
-                            }
-                            KeyCode::Char(key) => match key {
-                                'h' => {
-                                    hex_view.move_cursor_left();
-                                    status_label.clear();
-                                }
-                                'l' => {
-                                    hex_view.move_cursor_right();
+                                // The focused mode gets first refusal on printable keys (e.g.
+                                // AsciiMode wants most printable characters to overwrite the
+                                // byte at the cursor), except for RESERVED_NORMAL_KEYS, which
+                                // always fall through to the Normal-mode bindings below so
+                                // navigation/save/undo/goto still work no matter which pane
+                                // is focused.
+                                KeyCode::Char(key)
+                                    if hex_view.cursor.is_visible
+                                        && hex_view.handle_edit_key(KeyCode::Char(key)) =>
+                                {
                                     status_label.clear();
                                 }
-                                'j' => {
-                                    hex_view.move_cursor_down();
-                                    status_label.clear();
-                                }
-                                'k' => {
-                                    hex_view.move_cursor_up();
-                                    status_label.clear();
-                                }
-                                's' => {
-                                    let _ = file.seek(io::SeekFrom::Start(0));
-                                    match file.write_all(&hex_view.get_data_as_bytes()) {
+                                KeyCode::Char(key) => match key {
+                                    'h' => {
+                                        hex_view.move_cursor_left();
+                                        status_label.clear();
+                                    }
+                                    'l' => {
+                                        hex_view.move_cursor_right();
+                                        status_label.clear();
+                                    }
+                                    'j' => {
+                                        hex_view.move_cursor_down(visible_rows);
+                                        status_label.clear();
+                                    }
+                                    'k' => {
+                                        hex_view.move_cursor_up(visible_rows);
+                                        status_label.clear();
+                                    }
+                                    's' => match hex_view.save() {
                                         Ok(_) => {
                                             status_label = "File was saved...".to_string();
                                         }
                                         Err(_) => {
                                             status_label = "Could not save file...".to_string()
                                         }
+                                    },
+                                    'i' => {
+                                        hex_view.insert_byte_at_cursor(visible_rows);
+                                        status_label.clear();
                                     }
+                                    'x' => {
+                                        hex_view.delete_byte_at_cursor(visible_rows);
+                                        status_label.clear();
+                                    }
+                                    'u' => {
+                                        hex_view.undo(visible_rows);
+                                        status_label.clear();
+                                    }
+                                    ':' | 'g' => {
+                                        input_mode = InputMode::Command;
+                                        command_buffer.clear();
+                                    }
+                                    _ => {}
+                                },
+                                KeyCode::PageDown => {
+                                    hex_view.page_down(visible_rows);
+                                    status_label.clear();
+                                }
+                                KeyCode::PageUp => {
+                                    hex_view.page_up(visible_rows);
+                                    status_label.clear();
+                                }
+                                KeyCode::Tab => {
+                                    hex_view.cycle_mode();
+                                    status_label.clear();
+                                }
+                                KeyCode::Enter => {}
+                                _ => {}
+                            },
+                            InputMode::Command => match key_event.code {
+                                KeyCode::Char(c) => command_buffer.push(c),
+                                KeyCode::Backspace => {
+                                    command_buffer.pop();
+                                }
+                                KeyCode::Esc => {
+                                    input_mode = InputMode::Normal;
+                                    command_buffer.clear();
+                                }
+                                KeyCode::Enter => {
+                                    if let Some(offset) =
+                                        parse_offset(&command_buffer, hex_view.selected_offset())
+                                    {
+                                        hex_view.set_cursor_to_offset(offset, visible_rows);
+                                    }
+                                    input_mode = InputMode::Normal;
+                                    command_buffer.clear();
                                 }
                                 _ => {}
                             },
-                            KeyCode::Enter => {}
-                            _ => {}
                         }
                     }
                 }
@@ -388,7 +592,7 @@ fn main() -> Result<()> {
 
         status_bar(
             &mut buffer,
-            "HexEditor",
+            &format!("HexEditor [{}]", hex_view.focused_mode_name()),
             0,
             0,
             width.into(),
@@ -396,9 +600,18 @@ fn main() -> Result<()> {
             Color::White,
         );
 
-        render_hex_editor(&mut buffer, &hex_view, 0, 1);
+        render_hex_editor(&mut buffer, &mut hex_view, 0, 1, visible_rows);
+
+        let status_text = match input_mode {
+            InputMode::Command => format!(":{command_buffer}"),
+            InputMode::Normal if status_label.is_empty() => match hex_view.pending_edits() {
+                0 => String::new(),
+                pending => format!("{pending} pending edit(s)"),
+            },
+            InputMode::Normal => status_label.clone(),
+        };
 
-        status_bar(&mut buffer, &status_label, 0, height as usize - 1, width.into(), Color::Black, Color::White);
+        status_bar(&mut buffer, &status_text, 0, height as usize - 1, width.into(), Color::Black, Color::White);
 
         let patches = buffer.diff(&prev_buffer);
 
@@ -50,17 +50,32 @@ impl TerminalBuffer {
     }
 
     pub fn put_cell(&mut self, x: usize, y: usize, ch: char, fg: Color, bg: Color) {
-        let index = y * self.w + x;
+        if x >= self.w || y >= self.h {
+            return;
+        }
 
+        let index = y * self.w + x;
         if let Some(cell) = self.cells.get_mut(index) {
             *cell = Cell { ch, fg, bg };
         }
     }
 
+    /// Writes `chs` starting at `(x, y)`, clipping any characters that
+    /// would fall at or past column `w` instead of wrapping into the next
+    /// row.
     pub fn put_cells(&mut self, x: usize, y: usize, chs: &str, fg: Color, bg: Color) {
-        let start_index = y * self.w + x;
+        if y >= self.h {
+            return;
+        }
+
         for (i, ch) in chs.chars().enumerate() {
-            if let Some(cell) = self.cells.get_mut(start_index + i) {
+            let cx = x + i;
+            if cx >= self.w {
+                break;
+            }
+
+            let index = y * self.w + cx;
+            if let Some(cell) = self.cells.get_mut(index) {
                 *cell = Cell { ch, fg, bg }
             }
         }
@@ -113,12 +128,40 @@ impl TerminalBuffer {
     }
 }
 
+/// Groups `patches` (sorted by row, then column) into contiguous horizontal
+/// runs and emits one `MoveTo` per run instead of one per cell. Foreground
+/// and background colors are tracked across the whole patch list, the way
+/// `TerminalBuffer::flush` tracks them across the whole buffer, so a color
+/// is only re-emitted when it actually changes from the previous cell.
 pub fn apply_patches(qc: &mut impl QueueableCommand, patches: &[Patch]) -> io::Result<()> {
-    for Patch { cell, x, y } in patches.iter() {
-        qc.queue(MoveTo(*x as u16, *y as u16))?;
-        qc.queue(SetForegroundColor(cell.fg))?;
-        qc.queue(SetBackgroundColor(cell.bg))?;
-        qc.queue(Print(cell.ch))?;
+    let mut curr_fg = None;
+    let mut curr_bg = None;
+
+    let mut start = 0;
+    while start < patches.len() {
+        let mut end = start + 1;
+        while end < patches.len()
+            && patches[end].y == patches[end - 1].y
+            && patches[end].x == patches[end - 1].x + 1
+        {
+            end += 1;
+        }
+
+        qc.queue(MoveTo(patches[start].x as u16, patches[start].y as u16))?;
+
+        for Patch { cell, .. } in &patches[start..end] {
+            if curr_fg != Some(cell.fg) {
+                curr_fg = Some(cell.fg);
+                qc.queue(SetForegroundColor(cell.fg))?;
+            }
+            if curr_bg != Some(cell.bg) {
+                curr_bg = Some(cell.bg);
+                qc.queue(SetBackgroundColor(cell.bg))?;
+            }
+            qc.queue(Print(cell.ch))?;
+        }
+
+        start = end;
     }
 
     Ok(())